@@ -4,6 +4,8 @@ use std::result;
 use std::collections::VecDeque;
 use std::collections::HashSet;
 use std::collections::HashMap;
+use std::collections::BinaryHeap;
+use std::cmp::Ordering;
 use std::i64;
 
 pub type Result = result::Result<(), String>;
@@ -29,24 +31,29 @@ pub struct Vertex<A> {
 
 /// A directed graph
 pub struct DirectedGraph<A> {
-    vertices: Vec<Vertex<A>>
+    vertices: Vec<Option<Vertex<A>>>,
+    free_ids: Vec<usize>
 }
 
+/// The three-color marking used by `DirectedGraph::find_cycle`'s depth-first search
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Color { White, Gray, Black }
+
 impl <A> DirectedGraph<A> {
 
     /// Constructs a new empty directed graph
     pub fn new() -> DirectedGraph<A> {
-        DirectedGraph { vertices: Vec::new() }
+        DirectedGraph { vertices: Vec::new(), free_ids: Vec::new() }
     }
 
     /// Retrieves the vertex at the given id
     pub fn vertex(&self, id: VertexId) -> Option<&Vertex<A>> {
-        self.vertices.get(id.value)
+        self.vertices.get(id.value).and_then(|slot| slot.as_ref())
     }
 
     /// Retrieves the vertex at the given id
     pub fn vertex_mut(&mut self, id: VertexId) -> Option<&mut Vertex<A>> {
-        self.vertices.get_mut(id.value)
+        self.vertices.get_mut(id.value).and_then(|slot| slot.as_mut())
     }
 
     /// Retrieves a vertex value
@@ -61,9 +68,56 @@ impl <A> DirectedGraph<A> {
 
     /// Retrieves the vertex value from the graph
     pub fn add_vertex(&mut self, value: A) -> VertexId {
-        let id = VertexId { value: self.vertices.len() };
-        self.vertices.push(Vertex { value: value, arcs_out: Vec::new(), arcs_in: Vec::new(), id });
-        id
+        match self.free_ids.pop() {
+            Some(index) => {
+                let id = VertexId { value: index };
+                self.vertices[index] = Some(Vertex { value: value, arcs_out: Vec::new(), arcs_in: Vec::new(), id });
+                id
+            },
+            None => {
+                let id = VertexId { value: self.vertices.len() };
+                self.vertices.push(Some(Vertex { value: value, arcs_out: Vec::new(), arcs_in: Vec::new(), id }));
+                id
+            }
+        }
+    }
+
+    /// Removes a vertex and all arcs connecting it to other vertices, returning its value.
+    /// The removed id's slot can be reused by a later `add_vertex` call.
+    pub fn remove_vertex(&mut self, id: VertexId) -> Option<A> {
+        let removed = self.vertices.get_mut(id.value).and_then(|slot| slot.take())?;
+
+        for arc in &removed.arcs_out {
+            if let Some(other) = self.vertex_mut(arc.other) {
+                other.arcs_in.retain(|arc| arc.other != id);
+            }
+        }
+        for arc in &removed.arcs_in {
+            if let Some(other) = self.vertex_mut(arc.other) {
+                other.arcs_out.retain(|arc| arc.other != id);
+            }
+        }
+
+        self.free_ids.push(id.value);
+        Some(removed.value)
+    }
+
+    /// Removes every arc connecting `from` to `to`, returning whether any were removed
+    pub fn remove_edge(&mut self, from: VertexId, to: VertexId) -> bool {
+        let removed = match self.vertex_mut(from) {
+            Some(vertex) => {
+                let before = vertex.arcs_out.len();
+                vertex.arcs_out.retain(|arc| arc.other != to);
+                before != vertex.arcs_out.len()
+            },
+            None => false
+        };
+
+        if let Some(vertex) = self.vertex_mut(to) {
+            vertex.arcs_in.retain(|arc| arc.other != from);
+        }
+
+        removed
     }
 
     /// Connects two vertices
@@ -106,27 +160,57 @@ impl <A> DirectedGraph<A> {
     }
 
     pub fn is_empty(&self) -> bool {
-        self.vertices.is_empty()
+        self.vertices.iter().all(|slot| slot.is_none())
     }
 
     /// Checks if the graph is cyclic
     pub fn is_cyclic(&self) -> bool {
-        if self.is_empty() {
-            false
-        } else {
-            let head = &self.vertices[0];
-            for vertex in self.depth_first_iter(head.id) {
-                for arc in &vertex.arcs_out {
-                    let other_vertex = &self.vertices[arc.other.value];
-                    for reverse_arc in &other_vertex.arcs_out {
-                        if reverse_arc.other == vertex.id {
-                            return true
+        self.find_cycle().is_some()
+    }
+
+    /// Finds a cycle in the graph, if one exists, returning its vertices in order
+    pub fn find_cycle(&self) -> Option<Vec<VertexId>> {
+        let mut colors = vec![Color::White; self.vertices.len()];
+        let mut path = Vec::new();
+
+        for vertex in self.vertices.iter().filter_map(|slot| slot.as_ref()) {
+            if colors[vertex.id.value] == Color::White {
+                let cycle = self.find_cycle_from(vertex.id, &mut colors, &mut path);
+                if cycle.is_some() {
+                    return cycle;
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Depth-first-search from `id` using a three-color scheme, reporting the first cycle found
+    fn find_cycle_from(&self, id: VertexId, colors: &mut Vec<Color>, path: &mut Vec<VertexId>) -> Option<Vec<VertexId>> {
+        colors[id.value] = Color::Gray;
+        path.push(id);
+
+        if let Some(vertex) = self.vertex(id) {
+            for arc in &vertex.arcs_out {
+                match colors[arc.other.value] {
+                    Color::Gray => {
+                        let start = path.iter().position(|&v| v == arc.other).expect("back edge target must be on the current path");
+                        return Some(path[start..].to_vec());
+                    },
+                    Color::White => {
+                        let cycle = self.find_cycle_from(arc.other, colors, path);
+                        if cycle.is_some() {
+                            return cycle;
                         }
-                    }
+                    },
+                    Color::Black => ()
                 }
             }
-            return false;
         }
+
+        path.pop();
+        colors[id.value] = Color::Black;
+        None
     }
 
     /// The out-degree of a vertex
@@ -161,12 +245,13 @@ impl <A> DirectedGraph<A> {
     }
 
     fn topological_order(&self) -> Vec<VertexId> {
-        let mut last_counter = self.vertices.len();
+        let live_count = self.vertices.iter().filter_map(|slot| slot.as_ref()).count();
+        let mut last_counter = live_count;
         let mut order = Vec::new();
-        order.resize(self.vertices.len(), VertexId { value: 0 });
+        order.resize(live_count, VertexId { value: 0 });
         let mut visited = HashSet::with_capacity(self.vertices.len());
 
-        for vertex in &self.vertices {
+        for vertex in self.vertices.iter().filter_map(|slot| slot.as_ref()) {
             let result = self.dfs(
                 vertex.id,
                 &mut visited,
@@ -211,6 +296,168 @@ impl <A> DirectedGraph<A> {
             }
         }
     }
+
+    /// Returns the shortest distance from the source to each other reachable vertex, assuming
+    /// non-negative arc weights. Unlike `longest_distance_from`, this works on any graph,
+    /// cyclic or not, using Dijkstra's algorithm.
+    pub fn shortest_distance_from(&self, source: VertexId) -> HashMap<VertexId, i64> {
+        let mut distances = HashMap::new();
+        let mut heap = BinaryHeap::new();
+
+        distances.insert(source, 0);
+        heap.push(MinScored(0, source));
+
+        while let Some(MinScored(distance, vertex_id)) = heap.pop() {
+            if distance > *distances.get(&vertex_id).unwrap_or(&i64::MAX) {
+                continue;
+            }
+
+            if let Some(vertex) = self.vertex(vertex_id) {
+                for arc in &vertex.arcs_out {
+                    let next_distance = distance + arc.weight;
+                    if next_distance < *distances.get(&arc.other).unwrap_or(&i64::MAX) {
+                        distances.insert(arc.other, next_distance);
+                        heap.push(MinScored(next_distance, arc.other));
+                    }
+                }
+            }
+        }
+
+        distances
+    }
+
+    /// Runs A* search from `start` until a vertex satisfying `is_goal` is popped, guided by
+    /// `heuristic`. Assumes non-negative arc weights and an admissible heuristic. Returns the
+    /// total cost and the path from `start` to the goal, or `None` if no goal is reachable.
+    pub fn astar<G, H>(&self, start: VertexId, is_goal: G, heuristic: H) -> Option<(i64, Vec<VertexId>)>
+        where G: Fn(VertexId) -> bool, H: Fn(VertexId) -> i64 {
+
+        let mut g_score = HashMap::new();
+        let mut came_from = HashMap::new();
+        let mut open = BinaryHeap::new();
+
+        g_score.insert(start, 0);
+        open.push(MinScored(heuristic(start), start));
+
+        while let Some(MinScored(_, current)) = open.pop() {
+            if is_goal(current) {
+                let cost = g_score[&current];
+                let mut path = vec![current];
+                let mut node = current;
+                while let Some(&previous) = came_from.get(&node) {
+                    path.push(previous);
+                    node = previous;
+                }
+                path.reverse();
+                return Some((cost, path));
+            }
+
+            let current_g = g_score[&current];
+
+            if let Some(vertex) = self.vertex(current) {
+                for arc in &vertex.arcs_out {
+                    let next_g = current_g + arc.weight;
+                    if next_g < *g_score.get(&arc.other).unwrap_or(&i64::MAX) {
+                        g_score.insert(arc.other, next_g);
+                        came_from.insert(arc.other, current);
+                        open.push(MinScored(next_g + heuristic(arc.other), arc.other));
+                    }
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Computes the strongly connected components of the graph using Tarjan's algorithm.
+    /// Components are returned in no particular order; an explicit work stack is used in
+    /// place of recursion so large graphs do not overflow the call stack.
+    pub fn strongly_connected_components(&self) -> Vec<Vec<VertexId>> {
+        let mut index: Vec<Option<usize>> = vec![None; self.vertices.len()];
+        let mut lowlink = vec![0usize; self.vertices.len()];
+        let mut on_stack = vec![false; self.vertices.len()];
+        let mut stack = Vec::new();
+        let mut next_index = 0;
+        let mut components = Vec::new();
+
+        for start in self.vertices.iter().filter_map(|slot| slot.as_ref()) {
+            if index[start.id.value].is_some() {
+                continue;
+            }
+
+            let mut work = vec![TarjanFrame { vertex: start.id, arc_pos: 0 }];
+
+            while let Some(frame) = work.last_mut() {
+                let v = frame.vertex;
+
+                if index[v.value].is_none() {
+                    index[v.value] = Some(next_index);
+                    lowlink[v.value] = next_index;
+                    next_index += 1;
+                    stack.push(v);
+                    on_stack[v.value] = true;
+                }
+
+                let arcs_out = &self.vertex(v).expect("every vertex on the work stack must still exist").arcs_out;
+
+                if frame.arc_pos < arcs_out.len() {
+                    let w = arcs_out[frame.arc_pos].other;
+                    frame.arc_pos += 1;
+
+                    if index[w.value].is_none() {
+                        work.push(TarjanFrame { vertex: w, arc_pos: 0 });
+                    } else if on_stack[w.value] {
+                        lowlink[v.value] = lowlink[v.value].min(index[w.value].expect("w was just found to be indexed"));
+                    }
+                } else {
+                    work.pop();
+
+                    if let Some(parent) = work.last() {
+                        lowlink[parent.vertex.value] = lowlink[parent.vertex.value].min(lowlink[v.value]);
+                    }
+
+                    if lowlink[v.value] == index[v.value].expect("v was indexed when first visited") {
+                        let mut component = Vec::new();
+                        loop {
+                            let w = stack.pop().expect("the current SCC root must still be on the stack");
+                            on_stack[w.value] = false;
+                            component.push(w);
+                            if w == v {
+                                break;
+                            }
+                        }
+                        components.push(component);
+                    }
+                }
+            }
+        }
+
+        components
+    }
+}
+
+/// One stack frame of the iterative Tarjan's algorithm, tracking how far through a vertex's
+/// outgoing arcs the simulated recursion has progressed
+struct TarjanFrame {
+    vertex: VertexId,
+    arc_pos: usize
+}
+
+/// A (distance, vertex) pair ordered so that `BinaryHeap`, which is a max-heap, pops the
+/// smallest distance first
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+struct MinScored(i64, VertexId);
+
+impl Ord for MinScored {
+    fn cmp(&self, other: &MinScored) -> Ordering {
+        other.0.cmp(&self.0)
+    }
+}
+
+impl PartialOrd for MinScored {
+    fn partial_cmp(&self, other: &MinScored) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
 }
 
 /// Breadth-first Graph Iterator
@@ -228,14 +475,21 @@ impl <'a, A> Iterator for BFDirectedGraphIter<'a, A> {
                 self.next()
             },
             Some(arc) => {
-                let vertex = &self.graph.vertices[arc.other.value];
-                let mut sorted_arcs = vertex.arcs_out.clone();
-                sorted_arcs.sort_unstable_by_key(|arc| arc.weight);
-                for arc in sorted_arcs {
-                    self.q.push_back(arc);
+                match self.graph.vertex(arc.other) {
+                    Some(vertex) => {
+                        let mut sorted_arcs = vertex.arcs_out.clone();
+                        sorted_arcs.sort_unstable_by_key(|arc| arc.weight);
+                        for arc in sorted_arcs {
+                            self.q.push_back(arc);
+                        }
+                        self.visited[arc.other.value] = true;
+                        Some(vertex)
+                    },
+                    None => {
+                        self.visited[arc.other.value] = true;
+                        self.next()
+                    }
                 }
-                self.visited[arc.other.value] = true;
-                Some(&vertex)
             },
             _ => None
         }
@@ -257,14 +511,21 @@ impl <'a, A> Iterator for DFDirectedGraphIter<'a, A> {
                 self.next()
             },
             Some(arc) => {
-                let vertex = &self.graph.vertices[arc.other.value];
-                let mut sorted_arcs = vertex.arcs_out.clone();
-                sorted_arcs.sort_unstable_by_key(|arc| arc.weight);
-                for arc in sorted_arcs {
-                    self.stack.push(arc);
+                match self.graph.vertex(arc.other) {
+                    Some(vertex) => {
+                        let mut sorted_arcs = vertex.arcs_out.clone();
+                        sorted_arcs.sort_unstable_by_key(|arc| arc.weight);
+                        for arc in sorted_arcs {
+                            self.stack.push(arc);
+                        }
+                        self.visited[arc.other.value] = true;
+                        Some(vertex)
+                    },
+                    None => {
+                        self.visited[arc.other.value] = true;
+                        self.next()
+                    }
                 }
-                self.visited[arc.other.value] = true;
-                Some(&vertex)
             },
             _ => None
         }
@@ -273,8 +534,9 @@ impl <'a, A> Iterator for DFDirectedGraphIter<'a, A> {
 
 impl <A : fmt::Display> fmt::Display for DirectedGraph<A> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let _ = writeln!(f, "Graph of {} vertices:", self.vertices.len());
-        for vertex in self.vertices.iter() {
+        let vertices = self.vertices.iter().filter_map(|slot| slot.as_ref());
+        let _ = writeln!(f, "Graph of {} vertices:", vertices.clone().count());
+        for vertex in vertices {
             for arc in vertex.arcs_out.iter() {
                 let _ = writeln!(f, "\t ({}:{}) -(weight: {})-> ({}:{})",
                                  vertex.id,
@@ -288,6 +550,28 @@ impl <A : fmt::Display> fmt::Display for DirectedGraph<A> {
     }
 }
 
+impl <A : fmt::Display> DirectedGraph<A> {
+
+    /// Renders the graph as Graphviz DOT source, suitable for piping into `dot`
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::new();
+        dot.push_str("digraph {\n");
+
+        for vertex in self.vertices.iter().filter_map(|slot| slot.as_ref()) {
+            dot.push_str(&format!("    {} [label=\"{}\"]\n", vertex.id.value, vertex.value));
+        }
+
+        for vertex in self.vertices.iter().filter_map(|slot| slot.as_ref()) {
+            for arc in &vertex.arcs_out {
+                dot.push_str(&format!("    {} -> {} [label=\"{}\"]\n", vertex.id.value, arc.other.value, arc.weight));
+            }
+        }
+
+        dot.push_str("}\n");
+        dot
+    }
+}
+
 impl fmt::Display for VertexId {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "VertexId({})", self.value)
@@ -303,7 +587,7 @@ impl <'a, A> Iterator for TopologicalIter<'a, A> {
     type Item = &'a Vertex<A>;
     fn next(&mut self) -> Option<&'a Vertex<A>> {
         match self.order.pop() {
-            Some(id) => self.graph.vertex(id),
+            Some(id) => self.graph.vertex(id).or_else(|| self.next()),
             None => None
         }
     }
@@ -347,6 +631,97 @@ impl <A> UndirectedGraph<A> {
         self.directed.is_empty()
     }
 
+    /// The number of connected components in the graph
+    pub fn connected_components(&self) -> usize {
+        self.directed.vertices.iter()
+            .zip(self.component_labels())
+            .filter(|&(slot, _)| slot.is_some())
+            .map(|(_, label)| label)
+            .collect::<HashSet<_>>().len()
+    }
+
+    /// Labels each vertex with the representative of its connected component, so two vertices
+    /// share a label if and only if they are in the same component
+    pub fn component_labels(&self) -> Vec<usize> {
+        let vertices = &self.directed.vertices;
+        let mut union_find = UnionFind::new(vertices.len());
+
+        for vertex in vertices.iter().filter_map(|slot| slot.as_ref()) {
+            for arc in &vertex.arcs_out {
+                union_find.union(vertex.id.value, arc.other.value);
+            }
+        }
+
+        (0..vertices.len()).map(|i| union_find.find(i)).collect()
+    }
+
+    /// Computes a minimum spanning tree using Kruskal's algorithm. If the graph is disconnected
+    /// the result is a minimum spanning forest: one tree per connected component.
+    pub fn minimum_spanning_tree(&self) -> Vec<(VertexId, VertexId, i64)> {
+        let vertices = &self.directed.vertices;
+        let mut edges = Vec::new();
+
+        for vertex in vertices.iter().filter_map(|slot| slot.as_ref()) {
+            for arc in &vertex.arcs_out {
+                if vertex.id.value < arc.other.value {
+                    edges.push((vertex.id, arc.other, arc.weight));
+                }
+            }
+        }
+
+        edges.sort_by_key(|&(_, _, weight)| weight);
+
+        let mut union_find = UnionFind::new(vertices.len());
+        let mut tree = Vec::new();
+
+        for (from, to, weight) in edges {
+            if union_find.find(from.value) != union_find.find(to.value) {
+                union_find.union(from.value, to.value);
+                tree.push((from, to, weight));
+            }
+        }
+
+        tree
+    }
+
+}
+
+/// A disjoint-set structure with path compression and union by rank, used to compute connected
+/// components and minimum spanning trees
+struct UnionFind {
+    parent: Vec<usize>,
+    rank: Vec<usize>
+}
+
+impl UnionFind {
+
+    fn new(size: usize) -> UnionFind {
+        UnionFind { parent: (0..size).collect(), rank: vec![0; size] }
+    }
+
+    fn find(&mut self, x: usize) -> usize {
+        if self.parent[x] != x {
+            self.parent[x] = self.find(self.parent[x]);
+        }
+        self.parent[x]
+    }
+
+    fn union(&mut self, a: usize, b: usize) {
+        let (root_a, root_b) = (self.find(a), self.find(b));
+
+        if root_a == root_b {
+            return;
+        }
+
+        if self.rank[root_a] < self.rank[root_b] {
+            self.parent[root_a] = root_b;
+        } else if self.rank[root_a] > self.rank[root_b] {
+            self.parent[root_b] = root_a;
+        } else {
+            self.parent[root_b] = root_a;
+            self.rank[root_a] += 1;
+        }
+    }
 }
 
 impl <A: fmt::Display> fmt::Display for UndirectedGraph<A> {
@@ -355,6 +730,30 @@ impl <A: fmt::Display> fmt::Display for UndirectedGraph<A> {
     }
 }
 
+impl <A: fmt::Display> UndirectedGraph<A> {
+
+    /// Renders the graph as Graphviz DOT source, suitable for piping into `dot`
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::new();
+        dot.push_str("graph {\n");
+
+        for vertex in self.directed.vertices.iter().filter_map(|slot| slot.as_ref()) {
+            dot.push_str(&format!("    {} [label=\"{}\"]\n", vertex.id.value, vertex.value));
+        }
+
+        for vertex in self.directed.vertices.iter().filter_map(|slot| slot.as_ref()) {
+            for arc in &vertex.arcs_out {
+                if vertex.id.value < arc.other.value {
+                    dot.push_str(&format!("    {} -- {} [label=\"{}\"]\n", vertex.id.value, arc.other.value, arc.weight));
+                }
+            }
+        }
+
+        dot.push_str("}\n");
+        dot
+    }
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -404,6 +803,21 @@ mod tests {
         )
     }
 
+    #[test]
+    fn iterating_from_a_removed_vertex_yields_nothing() {
+
+        let mut graph = DirectedGraph::new();
+
+        let zero = graph.add_vertex("zero".to_string());
+        let one = graph.add_vertex("one".to_string());
+
+        graph.connect(zero, one, 0).unwrap();
+        graph.remove_vertex(zero);
+
+        assert!(graph.breadth_first_iter(zero).next().is_none());
+        assert!(graph.depth_first_iter(zero).next().is_none());
+    }
+
     #[test]
     fn is_empty() {
 
@@ -438,6 +852,160 @@ mod tests {
         assert!(graph.is_cyclic())
     }
 
+    #[test]
+    fn find_cycle_of_length_three() {
+
+        let mut graph = DirectedGraph::new();
+
+        let zero = graph.add_vertex("zero".to_string());
+        let one = graph.add_vertex("one".to_string());
+        let two = graph.add_vertex("two".to_string());
+
+        graph.connect(zero, one, 0).unwrap();
+        graph.connect(one, two, 0).unwrap();
+        graph.connect(two, zero, 0).unwrap();
+
+        assert_eq!(graph.find_cycle(), Some(vec![zero, one, two]));
+    }
+
+    #[test]
+    fn strongly_connected_components() {
+
+        let mut graph = DirectedGraph::new();
+
+        let zero = graph.add_vertex("zero".to_string());
+        let one = graph.add_vertex("one".to_string());
+        let two = graph.add_vertex("two".to_string());
+        let three = graph.add_vertex("three".to_string());
+
+        graph.connect(zero, one, 0).unwrap();
+        graph.connect(one, two, 0).unwrap();
+        graph.connect(two, zero, 0).unwrap();
+        graph.connect(two, three, 0).unwrap();
+
+        let mut components = graph.strongly_connected_components();
+        for component in components.iter_mut() {
+            component.sort_by_key(|id| id.value);
+        }
+        components.sort_by_key(|component| component[0].value);
+
+        assert_eq!(components, vec![vec![zero, one, two], vec![three]]);
+    }
+
+    #[test]
+    fn connected_components() {
+
+        let mut graph = UndirectedGraph::new();
+
+        let zero = graph.add_vertex("zero".to_string());
+        let one = graph.add_vertex("one".to_string());
+        let two = graph.add_vertex("two".to_string());
+        let three = graph.add_vertex("three".to_string());
+
+        graph.connect_undirected(zero, one, 0).unwrap();
+
+        assert_eq!(graph.connected_components(), 3);
+
+        let labels = graph.component_labels();
+        assert_eq!(labels[zero.value], labels[one.value]);
+        assert_ne!(labels[zero.value], labels[two.value]);
+        assert_ne!(labels[two.value], labels[three.value]);
+    }
+
+    #[test]
+    fn minimum_spanning_tree() {
+
+        let mut graph = UndirectedGraph::new();
+
+        let zero = graph.add_vertex("zero".to_string());
+        let one = graph.add_vertex("one".to_string());
+        let two = graph.add_vertex("two".to_string());
+
+        graph.connect_undirected(zero, one, 4).unwrap();
+        graph.connect_undirected(one, two, 1).unwrap();
+        graph.connect_undirected(zero, two, 2).unwrap();
+
+        assert_eq!(
+            graph.minimum_spanning_tree(),
+            vec![(one, two, 1), (zero, two, 2)]
+        );
+    }
+
+    #[test]
+    fn directed_to_dot() {
+
+        let mut graph = DirectedGraph::new();
+
+        let zero = graph.add_vertex("zero".to_string());
+        let one = graph.add_vertex("one".to_string());
+
+        graph.connect(zero, one, 5).unwrap();
+
+        let dot = graph.to_dot();
+
+        assert!(dot.starts_with("digraph {\n"));
+        assert!(dot.contains("0 [label=\"zero\"]"));
+        assert!(dot.contains("1 [label=\"one\"]"));
+        assert!(dot.contains("0 -> 1 [label=\"5\"]"));
+    }
+
+    #[test]
+    fn undirected_to_dot() {
+
+        let mut graph = UndirectedGraph::new();
+
+        let zero = graph.add_vertex("zero".to_string());
+        let one = graph.add_vertex("one".to_string());
+
+        graph.connect_undirected(zero, one, 5).unwrap();
+
+        let dot = graph.to_dot();
+
+        assert!(dot.starts_with("graph {\n"));
+        assert!(dot.contains("0 -- 1 [label=\"5\"]"));
+        assert!(!dot.contains("1 -- 0"));
+    }
+
+    #[test]
+    fn remove_vertex_prunes_dangling_arcs_and_reuses_id() {
+
+        let mut graph = DirectedGraph::new();
+
+        let zero = graph.add_vertex("zero".to_string());
+        let one = graph.add_vertex("one".to_string());
+        let two = graph.add_vertex("two".to_string());
+
+        graph.connect(zero, one, 0).unwrap();
+        graph.connect(one, two, 0).unwrap();
+
+        assert_eq!(graph.remove_vertex(one), Some("one".to_string()));
+        assert_eq!(graph.remove_vertex(one), None);
+
+        assert_eq!(graph.out_degree(zero), Some(0));
+        assert_eq!(graph.in_degree(two), Some(0));
+        assert!(graph.vertex(one).is_none());
+
+        let reused = graph.add_vertex("new-one".to_string());
+        assert_eq!(reused, one);
+    }
+
+    #[test]
+    fn remove_edge() {
+
+        let mut graph = DirectedGraph::new();
+
+        let zero = graph.add_vertex("zero".to_string());
+        let one = graph.add_vertex("one".to_string());
+
+        graph.connect(zero, one, 0).unwrap();
+
+        assert!(graph.remove_edge(zero, one));
+        assert!(!graph.remove_edge(zero, one));
+
+        assert_eq!(graph.out_degree(zero), Some(0));
+        assert_eq!(graph.in_degree(one), Some(0));
+    }
+
     #[test]
     fn out_and_in_degrees() {
 
@@ -488,6 +1056,26 @@ mod tests {
         )
     }
 
+    #[test]
+    fn topological_order_after_vertex_removal() {
+
+        let mut graph = DirectedGraph::new();
+
+        let a = graph.add_vertex("a".to_string());
+        let b = graph.add_vertex("b".to_string());
+        let c = graph.add_vertex("c".to_string());
+
+        graph.connect(a, b, 0).unwrap();
+        graph.connect(b, c, 0).unwrap();
+
+        graph.remove_vertex(a);
+
+        assert_eq!(
+            graph.topologically_ordered_iter().expect("Turns out acyclic").map(|v| v.value.to_string()).collect::<Vec<String>>(),
+            vec!["b", "c"]
+        )
+    }
+
     #[test]
     fn longest_path() {
         let mut graph = DirectedGraph::new();
@@ -517,4 +1105,47 @@ mod tests {
         assert_eq!(graph.longest_distance_from(one).unwrap()[&four], 8);
         assert_eq!(graph.longest_distance_from(one).unwrap()[&five], 10);
     }
+
+    #[test]
+    fn shortest_path() {
+        let mut graph = DirectedGraph::new();
+
+        let zero = graph.add_vertex("zero".to_string());
+        let one = graph.add_vertex("one".to_string());
+        let two = graph.add_vertex("two".to_string());
+        let three = graph.add_vertex("three".to_string());
+
+        graph.connect(zero, one, 4).unwrap();
+        graph.connect(zero, two, 1).unwrap();
+        graph.connect(two, one, 1).unwrap();
+        graph.connect(one, three, 1).unwrap();
+        graph.connect(three, zero, 1).unwrap();
+
+        let distances = graph.shortest_distance_from(zero);
+
+        assert_eq!(distances[&zero], 0);
+        assert_eq!(distances[&one], 2);
+        assert_eq!(distances[&two], 1);
+        assert_eq!(distances[&three], 3);
+    }
+
+    #[test]
+    fn astar_search() {
+        let mut graph = DirectedGraph::new();
+
+        let zero = graph.add_vertex("zero".to_string());
+        let one = graph.add_vertex("one".to_string());
+        let two = graph.add_vertex("two".to_string());
+        let three = graph.add_vertex("three".to_string());
+
+        graph.connect(zero, one, 4).unwrap();
+        graph.connect(zero, two, 1).unwrap();
+        graph.connect(two, one, 1).unwrap();
+        graph.connect(one, three, 1).unwrap();
+
+        let result = graph.astar(zero, |id| id == three, |_| 0);
+
+        assert_eq!(result, Some((3, vec![zero, two, one, three])));
+        assert_eq!(graph.astar(one, |id| id == zero, |_| 0), None);
+    }
 }